@@ -3,7 +3,12 @@ pub mod v1;
 use rocket::{routes, Route};
 
 pub mod auth;
+pub mod graphql;
+pub mod health;
 
 pub fn routes() -> Vec<Route> {
-    routes![auth::github_callback, auth::google_callback,]
+    let mut routes = routes![auth::github_callback, auth::google_callback,];
+    routes.extend(graphql::routes());
+    routes.extend(health::routes());
+    routes
 }
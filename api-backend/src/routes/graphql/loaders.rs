@@ -0,0 +1,145 @@
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use dataloader::non_cached::Loader;
+use dataloader::BatchFn;
+use diesel::prelude::*;
+use retronomicon_backend::db::Db;
+use retronomicon_backend::{models, schema};
+use rocket_db_pools::diesel::RunQueryDsl;
+use tokio::sync::Mutex;
+
+/// Batches a core's systems lookups for the request: collects the set of
+/// requested core ids during a tick and issues one
+/// `WHERE core_id = ANY($ids)` query instead of one query per core.
+struct SystemsByCoreBatcher {
+    db: Arc<Mutex<Db>>,
+}
+
+#[rocket::async_trait]
+impl BatchFn<i32, Vec<models::System>> for SystemsByCoreBatcher {
+    async fn load(&mut self, keys: &[i32]) -> HashMap<i32, Vec<models::System>> {
+        let mut db = self.db.lock().await;
+        let rows = schema::core_systems::table
+            .inner_join(schema::systems::table)
+            .filter(schema::core_systems::core_id.eq_any(keys))
+            .select((schema::core_systems::core_id, schema::systems::all_columns))
+            .load::<(i32, models::System)>(&mut *db)
+            .await
+            .unwrap_or_default();
+
+        let mut by_core: HashMap<i32, Vec<models::System>> =
+            keys.iter().map(|&k| (k, Vec::new())).collect();
+        for (core_id, system) in rows {
+            by_core.entry(core_id).or_default().push(system);
+        }
+        by_core
+    }
+}
+
+/// Batches a team's cores lookups the same way, keyed by team id.
+struct CoresByTeamBatcher {
+    db: Arc<Mutex<Db>>,
+}
+
+#[rocket::async_trait]
+impl BatchFn<i32, Vec<models::Core>> for CoresByTeamBatcher {
+    async fn load(&mut self, keys: &[i32]) -> HashMap<i32, Vec<models::Core>> {
+        let mut db = self.db.lock().await;
+        let rows = schema::cores::table
+            .filter(schema::cores::owner_team_id.eq_any(keys))
+            .select(models::Core::as_select())
+            .load::<models::Core>(&mut *db)
+            .await
+            .unwrap_or_default();
+
+        let mut by_team: HashMap<i32, Vec<models::Core>> =
+            keys.iter().map(|&k| (k, Vec::new())).collect();
+        for core in rows {
+            by_team.entry(core.owner_team_id).or_default().push(core);
+        }
+        by_team
+    }
+}
+
+/// Batches team-by-id lookups (a core's owning team).
+struct TeamByIdBatcher {
+    db: Arc<Mutex<Db>>,
+}
+
+#[rocket::async_trait]
+impl BatchFn<i32, Option<models::Team>> for TeamByIdBatcher {
+    async fn load(&mut self, keys: &[i32]) -> HashMap<i32, Option<models::Team>> {
+        let mut db = self.db.lock().await;
+        let rows = schema::teams::table
+            .filter(schema::teams::id.eq_any(keys))
+            .load::<models::Team>(&mut *db)
+            .await
+            .unwrap_or_default();
+
+        let mut by_id: HashMap<i32, Option<models::Team>> =
+            keys.iter().map(|&k| (k, None)).collect();
+        for team in rows {
+            by_id.insert(team.id, Some(team));
+        }
+        by_id
+    }
+}
+
+/// Batches a core's latest release lookup, keyed by core id.
+struct LatestReleaseByCoreBatcher {
+    db: Arc<Mutex<Db>>,
+}
+
+#[rocket::async_trait]
+impl BatchFn<i32, Option<models::CoreRelease>> for LatestReleaseByCoreBatcher {
+    async fn load(&mut self, keys: &[i32]) -> HashMap<i32, Option<models::CoreRelease>> {
+        let mut db = self.db.lock().await;
+        let rows = schema::core_releases::table
+            .filter(schema::core_releases::core_id.eq_any(keys))
+            .order((
+                schema::core_releases::core_id.asc(),
+                schema::core_releases::date_released.desc(),
+                schema::core_releases::id.desc(),
+            ))
+            .load::<models::CoreRelease>(&mut *db)
+            .await
+            .unwrap_or_default();
+
+        let mut by_core: HashMap<i32, Option<models::CoreRelease>> =
+            keys.iter().map(|&k| (k, None)).collect();
+        for release in rows {
+            // Rows are ordered newest first per core, so keep only the first.
+            let slot = by_core.entry(release.core_id).or_insert(None);
+            if slot.is_none() {
+                *slot = Some(release);
+            }
+        }
+        by_core
+    }
+}
+
+pub type SystemsByCoreLoader = Loader<i32, Vec<models::System>, SystemsByCoreBatcher>;
+pub type CoresByTeamLoader = Loader<i32, Vec<models::Core>, CoresByTeamBatcher>;
+pub type TeamByIdLoader = Loader<i32, Option<models::Team>, TeamByIdBatcher>;
+pub type LatestReleaseByCoreLoader = Loader<i32, Option<models::CoreRelease>, LatestReleaseByCoreBatcher>;
+
+/// Per-request bundle of batching loaders, all sharing the request's
+/// connection so nested resolvers don't fight over the pool.
+pub struct Loaders {
+    pub systems_by_core: SystemsByCoreLoader,
+    pub cores_by_team: CoresByTeamLoader,
+    pub team_by_id: TeamByIdLoader,
+    pub latest_release_by_core: LatestReleaseByCoreLoader,
+}
+
+impl Loaders {
+    pub fn new(db: Arc<Mutex<Db>>) -> Self {
+        Self {
+            systems_by_core: Loader::new(SystemsByCoreBatcher { db: db.clone() }),
+            cores_by_team: Loader::new(CoresByTeamBatcher { db: db.clone() }),
+            team_by_id: Loader::new(TeamByIdBatcher { db: db.clone() }),
+            latest_release_by_core: Loader::new(LatestReleaseByCoreBatcher { db }),
+        }
+    }
+}
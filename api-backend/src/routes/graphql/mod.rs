@@ -0,0 +1,68 @@
+mod loaders;
+mod schema;
+
+use std::sync::Arc;
+
+use retronomicon_backend::db::Db;
+use retronomicon_backend::models::EmbeddingProvider;
+use rocket::response::content::RawHtml;
+use rocket::{get, post, routes, Route, State};
+use tokio::sync::Mutex;
+
+pub use loaders::Loaders;
+pub use schema::{Query, Schema};
+
+/// Per-request GraphQL context. Note this is one DB connection per
+/// *request*, not per field: the connection is shared behind a mutex across
+/// the root resolvers and all four loaders, so every query in a request —
+/// batched or not — still serializes on that one connection. The batching
+/// is about collapsing N queries into 1, not about concurrent DB access.
+pub struct Context {
+    db: Arc<Mutex<Db>>,
+    loaders: Loaders,
+    embedding_provider: Arc<dyn EmbeddingProvider>,
+}
+
+impl juniper::Context for Context {}
+
+impl Context {
+    fn new(db: Db, embedding_provider: Arc<dyn EmbeddingProvider>) -> Self {
+        let db = Arc::new(Mutex::new(db));
+        Self {
+            loaders: Loaders::new(db.clone()),
+            db,
+            embedding_provider,
+        }
+    }
+}
+
+#[get("/graphql?<request>")]
+async fn graphql_get(
+    db: Db,
+    request: juniper_rocket::GraphQLRequest,
+    schema: &State<Schema>,
+    embedding_provider: &State<Arc<dyn EmbeddingProvider>>,
+) -> juniper_rocket::GraphQLResponse {
+    let ctx = Context::new(db, embedding_provider.inner().clone());
+    request.execute(&*schema, &ctx).await
+}
+
+#[post("/graphql", data = "<request>")]
+async fn graphql_post(
+    db: Db,
+    request: juniper_rocket::GraphQLRequest,
+    schema: &State<Schema>,
+    embedding_provider: &State<Arc<dyn EmbeddingProvider>>,
+) -> juniper_rocket::GraphQLResponse {
+    let ctx = Context::new(db, embedding_provider.inner().clone());
+    request.execute(&*schema, &ctx).await
+}
+
+#[get("/graphiql")]
+fn graphiql() -> RawHtml<String> {
+    juniper_rocket::graphiql_source("/graphql", None)
+}
+
+pub fn routes() -> Vec<Route> {
+    routes![graphql_get, graphql_post, graphiql]
+}
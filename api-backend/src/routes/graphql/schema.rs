@@ -0,0 +1,219 @@
+use diesel::prelude::*;
+use juniper::{graphql_object, EmptyMutation, EmptySubscription, FieldResult, RootNode};
+use retronomicon_backend::{models, schema as db_schema};
+use rocket_db_pools::diesel::RunQueryDsl;
+
+use super::Context;
+
+pub struct Core(models::Core);
+pub struct System(models::System);
+pub struct Team(models::Team);
+pub struct CoreRelease(models::CoreRelease);
+
+#[graphql_object(context = Context)]
+impl Core {
+    fn id(&self) -> i32 {
+        self.0.id
+    }
+
+    fn slug(&self) -> &str {
+        &self.0.slug
+    }
+
+    fn name(&self) -> &str {
+        &self.0.name
+    }
+
+    fn description(&self) -> &str {
+        &self.0.description
+    }
+
+    /// Batched through `systems_by_core`, so fetching systems for a page of
+    /// cores issues one query, not one per core.
+    async fn systems(&self, ctx: &Context) -> FieldResult<Vec<System>> {
+        let systems = ctx.loaders.systems_by_core.load(self.0.id).await;
+        Ok(systems.into_iter().map(System).collect())
+    }
+
+    async fn team(&self, ctx: &Context) -> FieldResult<Team> {
+        let team = ctx.loaders.team_by_id.load(self.0.owner_team_id).await;
+        team.map(Team)
+            .ok_or_else(|| format!("core {} has no owning team", self.0.id).into())
+    }
+
+    async fn latest_release(&self, ctx: &Context) -> FieldResult<Option<CoreRelease>> {
+        Ok(ctx
+            .loaders
+            .latest_release_by_core
+            .load(self.0.id)
+            .await
+            .map(CoreRelease))
+    }
+}
+
+#[graphql_object(context = Context)]
+impl System {
+    fn id(&self) -> i32 {
+        self.0.id
+    }
+
+    fn slug(&self) -> &str {
+        &self.0.slug
+    }
+
+    fn name(&self) -> &str {
+        &self.0.name
+    }
+}
+
+#[graphql_object(context = Context)]
+impl Team {
+    fn id(&self) -> i32 {
+        self.0.id
+    }
+
+    fn slug(&self) -> &str {
+        &self.0.slug
+    }
+
+    fn name(&self) -> &str {
+        &self.0.name
+    }
+
+    /// Batched through `cores_by_team`.
+    async fn cores(&self, ctx: &Context) -> FieldResult<Vec<Core>> {
+        let cores = ctx.loaders.cores_by_team.load(self.0.id).await;
+        Ok(cores.into_iter().map(Core).collect())
+    }
+}
+
+#[graphql_object(context = Context)]
+impl CoreRelease {
+    fn id(&self) -> i32 {
+        self.0.id
+    }
+
+    fn core_id(&self) -> i32 {
+        self.0.core_id
+    }
+}
+
+/// A core matched by `coresSemanticSearch`, alongside its team and systems.
+pub struct CoreSemanticMatch {
+    core: Core,
+    team: Team,
+    systems: Vec<System>,
+}
+
+#[graphql_object(context = Context)]
+impl CoreSemanticMatch {
+    fn core(&self) -> &Core {
+        &self.core
+    }
+
+    fn team(&self) -> &Team {
+        &self.team
+    }
+
+    fn systems(&self) -> &[System] {
+        &self.systems
+    }
+}
+
+/// A core matched by `searchCores`, alongside its relevance rank.
+pub struct CoreSearchMatch {
+    core: Core,
+    rank: f32,
+}
+
+#[graphql_object(context = Context)]
+impl CoreSearchMatch {
+    fn core(&self) -> &Core {
+        &self.core
+    }
+
+    fn rank(&self) -> f64 {
+        self.rank as f64
+    }
+}
+
+pub struct Query;
+
+#[graphql_object(context = Context)]
+impl Query {
+    async fn core(ctx: &Context, id: i32) -> FieldResult<Option<Core>> {
+        let mut db = ctx.db.lock().await;
+        let core = db_schema::cores::table
+            .filter(db_schema::cores::id.eq(id))
+            .first::<models::Core>(&mut *db)
+            .await
+            .optional()?;
+        Ok(core.map(Core))
+    }
+
+    async fn cores(ctx: &Context, page: i32, limit: i32) -> FieldResult<Vec<Core>> {
+        let mut db = ctx.db.lock().await;
+        let cores = models::Core::list(&mut db, page as i64, limit as i64).await?;
+        Ok(cores.into_iter().map(Core).collect())
+    }
+
+    /// Full-text search over core name/description, ranked by relevance.
+    async fn search_cores(
+        ctx: &Context,
+        query: String,
+        page: i32,
+        limit: i32,
+    ) -> FieldResult<Vec<CoreSearchMatch>> {
+        let mut db = ctx.db.lock().await;
+        let matches = models::Core::search(
+            &mut db,
+            &query,
+            page as i64,
+            limit as i64,
+            None,
+            None,
+            None,
+        )
+        .await?;
+        Ok(matches
+            .into_iter()
+            .map(|(core, rank)| CoreSearchMatch {
+                core: Core(core),
+                rank,
+            })
+            .collect())
+    }
+
+    /// Searches cores by natural-language description. Accepts either a
+    /// precomputed `embedding` or raw `text`, embedded server-side via the
+    /// configured `EmbeddingProvider`.
+    async fn cores_semantic_search(
+        ctx: &Context,
+        text: Option<String>,
+        embedding: Option<Vec<f64>>,
+        limit: i32,
+    ) -> FieldResult<Vec<CoreSemanticMatch>> {
+        let embedding = match (embedding, text) {
+            (Some(vector), _) => vector.into_iter().map(|v| v as f32).collect(),
+            (None, Some(text)) => ctx.embedding_provider.embed(&text).await?,
+            (None, None) => {
+                return Err(juniper::FieldError::from(
+                    "one of `text` or `embedding` is required",
+                ))
+            }
+        };
+
+        let mut db = ctx.db.lock().await;
+        let matches = models::Core::search_semantic(&mut db, &embedding, limit as i64).await?;
+        Ok(matches
+            .into_iter()
+            .map(|(core, team, systems)| CoreSemanticMatch {
+                core: Core(core),
+                team: Team(team),
+                systems: systems.into_iter().map(System).collect(),
+            })
+            .collect())
+    }
+}
+
+pub type Schema = RootNode<'static, Query, EmptyMutation<Context>, EmptySubscription<Context>>;
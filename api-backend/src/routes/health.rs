@@ -0,0 +1,24 @@
+use retronomicon_backend::db::{Db, PoolStatus};
+use rocket::serde::json::Json;
+use rocket::{get, routes, Route};
+use serde::Serialize;
+
+#[derive(Serialize)]
+struct Health {
+    ready: bool,
+    pool: PoolStatus,
+}
+
+/// Runs a `SELECT 1` against the pool and reports its occupancy, so a
+/// deployment behind a load balancer can gate traffic on DB availability.
+#[get("/health")]
+async fn health(db: Db) -> Json<Health> {
+    Json(Health {
+        ready: db.is_ready().await,
+        pool: db.pool_status(),
+    })
+}
+
+pub fn routes() -> Vec<Route> {
+    routes![health]
+}
@@ -0,0 +1,51 @@
+use chrono::NaiveDateTime;
+use clap::Subcommand;
+
+use crate::db::Db;
+use crate::models::Core;
+
+#[derive(Subcommand, Debug)]
+pub enum CoresCommand {
+    /// List cores, optionally only those released on or after a given date.
+    List {
+        #[arg(long)]
+        released_after: Option<NaiveDateTime>,
+        #[arg(long, default_value_t = 0)]
+        page: i64,
+        #[arg(long, default_value_t = 50)]
+        limit: i64,
+    },
+    /// Remove cores that have no releases.
+    Prune,
+}
+
+impl CoresCommand {
+    pub async fn run(&self, db: &mut Db) -> Result<(), diesel::result::Error> {
+        match self {
+            CoresCommand::List {
+                released_after,
+                page,
+                limit,
+            } => {
+                let cores = Core::list_with_teams_and_releases(
+                    db,
+                    *page,
+                    *limit,
+                    None,
+                    None,
+                    None,
+                    *released_after,
+                )
+                .await?;
+                for (core, _systems, team, _release, _platform) in cores {
+                    println!("{:>6}  {:<24} {}", core.id, core.slug, team.slug);
+                }
+            }
+            CoresCommand::Prune => {
+                let pruned = Core::prune_unreleased(db).await?;
+                println!("pruned {pruned} core(s) with no releases");
+            }
+        }
+        Ok(())
+    }
+}
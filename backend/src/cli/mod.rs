@@ -0,0 +1,36 @@
+use clap::{Parser, Subcommand};
+
+use crate::db::Db;
+use crate::migrations;
+
+mod cores;
+pub use cores::CoresCommand;
+
+/// Operator-facing maintenance commands.
+#[derive(Parser, Debug)]
+#[command(name = "retronomicon", about = "Retronomicon maintenance CLI")]
+pub struct Cli {
+    #[command(subcommand)]
+    pub command: Command,
+}
+
+#[derive(Subcommand, Debug)]
+pub enum Command {
+    /// Apply any pending database migrations.
+    Migrate,
+    /// Inspect and maintain `cores` rows.
+    Cores {
+        #[command(subcommand)]
+        command: CoresCommand,
+    },
+}
+
+impl Cli {
+    pub async fn run(&self, database_url: &str, db: &mut Db) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+        match &self.command {
+            Command::Migrate => migrations::run_pending_migrations(database_url)?,
+            Command::Cores { command } => command.run(db).await?,
+        }
+        Ok(())
+    }
+}
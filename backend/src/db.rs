@@ -0,0 +1,61 @@
+use rocket_db_pools::diesel::PgPool;
+use rocket_db_pools::Database;
+use serde::Serialize;
+
+/// `#[derive(Database)]` reads `rocket_db_pools::Config` out of Rocket's
+/// Figment config under `databases.retronomicon`; there's no separate
+/// config struct to wire up. To size the pool, set e.g.:
+///
+/// ```toml
+/// [default.databases.retronomicon]
+/// url = "postgres://..."
+/// max_connections = 16
+/// min_connections = 2
+/// connect_timeout = 5
+/// idle_timeout = 60
+/// ```
+///
+/// `rocket_db_pools::Config` has no equivalent of a recycle-on-checkout
+/// check or a separate acquire timeout distinct from `connect_timeout` —
+/// tuning those would mean building the deadpool `Pool` by hand instead of
+/// through this derive.
+#[derive(Database)]
+#[database("retronomicon")]
+pub struct Db(PgPool);
+
+/// Point-in-time view of the pool, returned by `/health` so deployments
+/// behind a load balancer can gate traffic on DB availability.
+#[derive(Debug, Serialize)]
+pub struct PoolStatus {
+    pub in_use: usize,
+    pub idle: usize,
+    pub max_size: usize,
+}
+
+impl Db {
+    /// Validates a live connection with a trivial round-trip query.
+    pub async fn is_ready(&self) -> bool {
+        use diesel::prelude::*;
+        use rocket_db_pools::diesel::RunQueryDsl;
+
+        let Ok(mut conn) = self.get().await else {
+            return false;
+        };
+
+        diesel::select(diesel::dsl::sql::<diesel::sql_types::Integer>("1"))
+            .get_result::<i32>(&mut conn)
+            .await
+            .is_ok()
+    }
+
+    /// Current pool occupancy, used by the `/health` route.
+    pub fn pool_status(&self) -> PoolStatus {
+        let status = self.0.status();
+        let idle = status.available.max(0) as usize;
+        PoolStatus {
+            in_use: status.size.saturating_sub(idle),
+            idle,
+            max_size: status.max_size,
+        }
+    }
+}
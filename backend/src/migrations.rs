@@ -0,0 +1,40 @@
+use diesel::{Connection, PgConnection};
+use diesel_migrations::{embed_migrations, EmbeddedMigrations, MigrationHarness};
+use rocket::fairing::AdHoc;
+
+pub const MIGRATIONS: EmbeddedMigrations = embed_migrations!("migrations");
+
+/// Opens a sync connection directly, since diesel's migration harness doesn't
+/// run on the async pool used to serve requests.
+pub fn run_pending_migrations(
+    database_url: &str,
+) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+    let mut conn = PgConnection::establish(database_url)?;
+    conn.run_pending_migrations(MIGRATIONS)?;
+    Ok(())
+}
+
+/// Applies pending migrations during ignite, before routes come up.
+/// Attach with `.attach(migrations::fairing())`.
+pub fn fairing() -> AdHoc {
+    AdHoc::try_on_ignite("Database Migrations", |rocket| async {
+        let database_url = match rocket
+            .figment()
+            .extract_inner::<String>("databases.retronomicon.url")
+        {
+            Ok(url) => url,
+            Err(e) => {
+                rocket::error!("failed to read database url for migrations: {e}");
+                return Err(rocket);
+            }
+        };
+
+        match run_pending_migrations(&database_url) {
+            Ok(()) => Ok(rocket),
+            Err(e) => {
+                rocket::error!("failed to run pending migrations: {e}");
+                Err(rocket)
+            }
+        }
+    })
+}
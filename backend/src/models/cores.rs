@@ -7,8 +7,11 @@ use diesel::{AsExpression, FromSqlRow, Identifiable, Queryable};
 use retronomicon_dto as dto;
 use rocket_db_pools::diesel::{AsyncConnection, RunQueryDsl};
 use serde_json::Value as Json;
+use std::collections::HashMap;
 
+mod embeddings;
 mod releases;
+pub use embeddings::*;
 pub use releases::*;
 
 #[derive(Identifiable, Selectable, Queryable, Associations, Debug)]
@@ -161,33 +164,101 @@ impl Core {
             )>(db)
             .await?;
 
-        let all_cores = schema::cores::table
-            .select(Core::as_select())
-            .load(db)
-            .await?;
+        // Only prefetch systems for the cores on this page, keyed by core id,
+        // instead of loading every core in the table to drive `grouped_by`.
+        let core_ids = cores.iter().map(|(core, ..)| core.id).collect::<Vec<_>>();
 
-        let systems = CoreSystems::belonging_to(&cores.iter().map(|r| r.0).collect::<Vec<_>>())
+        let systems = schema::core_systems::table
+            .filter(schema::core_systems::core_id.eq_any(&core_ids))
             .inner_join(schema::systems::table)
-            .select((CoreSystems::as_select(), System::as_select()))
-            .load(db)
+            .select((schema::core_systems::core_id, System::as_select()))
+            .load::<(i32, models::System)>(db)
             .await?;
 
+        let mut systems_by_core_id: HashMap<i32, Vec<models::System>> = HashMap::new();
+        for (core_id, system) in systems {
+            systems_by_core_id.entry(core_id).or_default().push(system);
+        }
+
         let cores_with_systems: Vec<(
             Self,
             Vec<models::System>,
             models::Team,
             Option<models::CoreRelease>,
             models::Platform,
-        )> = systems
-            .grouped_by(&all_cores)
+        )> = cores
             .into_iter()
-            .zip(cores)
-            .map(|(systems, core)| (core.0, systems, core.1, core.2, core.3))
+            .map(|(core, team, release, platform)| {
+                let systems = systems_by_core_id.remove(&core.id).unwrap_or_default();
+                (core, systems, team, release, platform)
+            })
             .collect::<Vec<_>>();
 
         Ok(cores_with_systems)
     }
 
+    /// Full-text search over `name`/`description`, ranked by `ts_rank`.
+    pub async fn search(
+        db: &mut Db,
+        query: &str,
+        page: i64,
+        limit: i64,
+        platform: Option<&Platform>,
+        system: Option<&System>,
+        team: Option<&Team>,
+    ) -> Result<Vec<(Self, f32)>, diesel::result::Error> {
+        let matches = diesel::dsl::sql::<diesel::sql_types::Bool>(
+            "cores.search_vector @@ plainto_tsquery(",
+        )
+        .bind::<diesel::sql_types::Text, _>(query.to_string())
+        .sql(")");
+
+        let rank = diesel::dsl::sql::<diesel::sql_types::Float4>(
+            "ts_rank(cores.search_vector, plainto_tsquery(",
+        )
+        .bind::<diesel::sql_types::Text, _>(query.to_string())
+        .sql("))");
+
+        let order_by_rank = diesel::dsl::sql::<diesel::sql_types::Float4>(
+            "ts_rank(cores.search_vector, plainto_tsquery(",
+        )
+        .bind::<diesel::sql_types::Text, _>(query.to_string())
+        .sql(")) DESC");
+
+        let mut boxed_query = schema::cores::table
+            .inner_join(schema::teams::table)
+            .filter(matches)
+            .into_boxed();
+
+        if let Some(team) = team {
+            boxed_query = boxed_query.filter(schema::teams::id.eq(team.id));
+        }
+
+        if let Some(system) = system {
+            boxed_query = boxed_query.filter(diesel::dsl::exists(
+                schema::core_systems::table
+                    .filter(schema::core_systems::core_id.eq(schema::cores::id))
+                    .filter(schema::core_systems::system_id.eq(system.id)),
+            ));
+        }
+
+        if let Some(platform) = platform {
+            boxed_query = boxed_query.filter(diesel::dsl::exists(
+                schema::core_releases::table
+                    .filter(schema::core_releases::core_id.eq(schema::cores::id))
+                    .filter(schema::core_releases::platform_id.eq(platform.id)),
+            ));
+        }
+
+        boxed_query
+            .select((schema::cores::all_columns, rank))
+            .order(order_by_rank)
+            .offset(page * limit)
+            .limit(limit)
+            .load::<(Self, f32)>(db)
+            .await
+    }
+
     pub async fn create(
         db: &mut Db,
         slug: &str,
@@ -197,6 +268,7 @@ impl Core {
         links: Json,
         systems: &[models::System],
         owner_team: &models::Team,
+        embedding_provider: Option<&dyn EmbeddingProvider>,
     ) -> Result<Self, diesel::result::Error> {
         let result = diesel::insert_into(schema::cores::table)
             .values((
@@ -225,6 +297,11 @@ impl Core {
             )
             .execute(db)
             .await?;
+
+        if let Some(provider) = embedding_provider {
+            result.update_embedding(db, provider).await?;
+        }
+
         Ok(result)
     }
 
@@ -260,4 +337,14 @@ impl Core {
 
         Ok(Some((results.0, results.1, systems)))
     }
+
+    /// Removes cores that have no releases. Used by the `cores prune`
+    /// maintenance CLI command.
+    pub async fn prune_unreleased(db: &mut Db) -> Result<usize, diesel::result::Error> {
+        diesel::delete(schema::cores::table.filter(diesel::dsl::not(diesel::dsl::exists(
+            schema::core_releases::table.filter(schema::core_releases::core_id.eq(schema::cores::id)),
+        ))))
+        .execute(db)
+        .await
+    }
 }
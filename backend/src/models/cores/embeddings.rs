@@ -0,0 +1,91 @@
+use diesel::prelude::*;
+use rocket_db_pools::diesel::{AsyncConnection, RunQueryDsl};
+use std::collections::HashMap;
+
+use crate::db::Db;
+use crate::{models, schema};
+
+use super::Core;
+
+/// Turns text into an embedding vector; a trait object so the model provider is pluggable.
+#[rocket::async_trait]
+pub trait EmbeddingProvider: Send + Sync {
+    async fn embed(&self, text: &str) -> Result<Vec<f32>, diesel::result::Error>;
+}
+
+#[derive(Queryable, Debug, Identifiable, Selectable)]
+#[diesel(primary_key(core_id))]
+#[diesel(belongs_to(Core))]
+#[diesel(table_name = schema::core_embeddings)]
+pub struct CoreEmbedding {
+    pub core_id: i32,
+    pub embedding: Vec<f32>,
+}
+
+impl Core {
+    /// Recomputes and upserts the embedding from name + description + metadata.
+    pub async fn update_embedding(
+        &self,
+        db: &mut Db,
+        provider: &dyn EmbeddingProvider,
+    ) -> Result<(), diesel::result::Error> {
+        let text = format!("{}\n{}\n{}", self.name, self.description, self.metadata);
+        let embedding = provider.embed(&text).await?;
+
+        diesel::insert_into(schema::core_embeddings::table)
+            .values((
+                schema::core_embeddings::core_id.eq(self.id),
+                schema::core_embeddings::embedding.eq(&embedding),
+            ))
+            .on_conflict(schema::core_embeddings::core_id)
+            .do_update()
+            .set(schema::core_embeddings::embedding.eq(&embedding))
+            .execute(db)
+            .await?;
+
+        Ok(())
+    }
+
+    /// Orders cores by cosine distance to `query_embedding`, closest first.
+    pub async fn search_semantic(
+        db: &mut Db,
+        query_embedding: &[f32],
+        limit: i64,
+    ) -> Result<Vec<(Self, models::Team, Vec<models::System>)>, diesel::result::Error> {
+        let distance = diesel::dsl::sql::<diesel::sql_types::Float4>(
+            "core_embeddings.embedding <=> ",
+        )
+        .bind::<diesel::sql_types::Array<diesel::sql_types::Float4>, _>(query_embedding.to_vec())
+        .sql("::vector");
+
+        let cores = schema::cores::table
+            .inner_join(schema::core_embeddings::table)
+            .inner_join(schema::teams::table)
+            .select((schema::cores::all_columns, schema::teams::all_columns))
+            .order(distance)
+            .limit(limit)
+            .load::<(Self, models::Team)>(db)
+            .await?;
+
+        let core_ids = cores.iter().map(|(core, _)| core.id).collect::<Vec<_>>();
+        let systems = schema::core_systems::table
+            .filter(schema::core_systems::core_id.eq_any(&core_ids))
+            .inner_join(schema::systems::table)
+            .select((schema::core_systems::core_id, models::System::as_select()))
+            .load::<(i32, models::System)>(db)
+            .await?;
+
+        let mut systems_by_core_id: HashMap<i32, Vec<models::System>> = HashMap::new();
+        for (core_id, system) in systems {
+            systems_by_core_id.entry(core_id).or_default().push(system);
+        }
+
+        Ok(cores
+            .into_iter()
+            .map(|(core, team)| {
+                let systems = systems_by_core_id.remove(&core.id).unwrap_or_default();
+                (core, team, systems)
+            })
+            .collect())
+    }
+}